@@ -4,9 +4,7 @@ use id_token_verifier::id_token_verifier::IdTokenVerifier;
 use reqwest::Client;
 use serde::Deserialize;
 
-use tokio;
-
-use id_token_verifier::prelude::{FetchSource, JwkBasedJwtIdTokenVerifierBuilder};
+use id_token_verifier::prelude::{FetchSource, JwkBasedJwtIdTokenVerifier};
 
 #[tokio::main]
 async fn main() {
@@ -16,7 +14,7 @@ async fn main() {
             .unwrap(),
     };
 
-    let id_token_verifier = JwkBasedJwtIdTokenVerifierBuilder::new(fetch_source)
+    let id_token_verifier = JwkBasedJwtIdTokenVerifier::builder(fetch_source)
         .with_http_client(Client::new())
         .with_cache(Duration::seconds(10))
         .with_validation_options(
@@ -29,6 +27,7 @@ async fn main() {
         .build();
 
     #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
     struct Payload {
         iat: i64,
         exp: i64,
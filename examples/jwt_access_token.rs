@@ -0,0 +1,40 @@
+use chrono::Duration;
+use id_token_verifier::error::Error;
+use reqwest::Client;
+use serde::Deserialize;
+
+use id_token_verifier::prelude::{FetchSource, JwkBasedJwtIdTokenVerifier};
+
+#[tokio::main]
+async fn main() {
+    let fetch_source = FetchSource::AutoDiscover {
+        url: "https://your-tenant.example.com/.well-known/openid-configuration"
+            .parse()
+            .unwrap(),
+    };
+
+    let jwt_verifier = JwkBasedJwtIdTokenVerifier::builder(fetch_source)
+        .with_http_client(Client::new())
+        .with_cache(Duration::minutes(10))
+        .with_validation_options(
+            vec![String::from("https://your-tenant.example.com/")],
+            // In real app, this should be the identifier of your API
+            vec![String::from("https://api.example.com")],
+        )
+        .build();
+
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Claims {
+        exp: i64,
+        sub: String,
+        scope: String,
+    }
+
+    // Paste the JWT access token issued for your API here
+    let access_token = "paste access token here";
+
+    let result: Result<Claims, Error> = jwt_verifier.verify_jwt(access_token).await;
+
+    println!("{result:?}");
+}
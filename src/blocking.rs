@@ -0,0 +1,101 @@
+use tokio::runtime::{Builder, Runtime};
+
+use crate::prelude::*;
+
+/// A blocking wrapper of an [IdTokenVerifier] for synchronous code, that drives the verification on an internal
+/// current-thread [Runtime].
+///
+/// Its methods must not be called from within an asynchronous context: they panic if called from a thread that drives
+/// a [Runtime]. Tasks spawned by the verifier (e.g. the background refresh) are not spawned unless the verifier is
+/// built within a [Runtime], so prefer the on-demand fetches of the cache with this wrapper.
+pub struct BlockingIdTokenVerifier<Verifier> {
+    /// An [IdTokenVerifier] to drive.
+    verifier: Verifier,
+
+    /// A current-thread [Runtime] that drives the `verifier`.
+    runtime: Runtime,
+}
+
+impl<Verifier> BlockingIdTokenVerifier<Verifier> {
+    /// Returns a new instance of the [BlockingIdTokenVerifier] that wraps the given [IdTokenVerifier], or an
+    /// [Err(std::io::Error)] if the internal [Runtime] cannot be created.
+    pub fn new(verifier: Verifier) -> std::io::Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+
+        Ok(Self { verifier, runtime })
+    }
+
+    /// Verifies the given `id_token` like [IdTokenVerifier::verify], blocking the current thread until it completes.
+    ///
+    /// Returns [Ok(Payload)] if verification succeeds or an [Err(Error)] otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an asynchronous context.
+    pub fn verify<Payload>(&self, id_token: &str) -> Result<Payload, Error>
+    where
+        Verifier: IdTokenVerifier<Payload>,
+    {
+        self.runtime.block_on(self.verifier.verify(id_token))
+    }
+
+    /// Returns a reference to the wrapped [IdTokenVerifier].
+    pub fn inner(&self) -> &Verifier {
+        &self.verifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::AssertUnwindSafe;
+
+    use crate::blocking::*;
+
+    #[test]
+    fn test_verify() {
+        let verifier = BlockingIdTokenVerifier::new(TestIdTokenVerifier).unwrap();
+
+        let result: Result<String, Error> = verifier.verify("id_token");
+        assert_eq!(result.unwrap(), "id_token");
+
+        let result: Result<String, Error> = verifier.verify("");
+        assert!(matches!(
+            result,
+            Err(Error::IdTokenError {
+                kind: IdTokenErrorKind::MalformedHeader,
+                source: None,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_within_async_context() {
+        let verifier = BlockingIdTokenVerifier::new(TestIdTokenVerifier).unwrap();
+        let runtime = Builder::new_current_thread().build().unwrap();
+
+        let result = runtime.block_on(async {
+            std::panic::catch_unwind(AssertUnwindSafe(|| {
+                let _: Result<String, Error> = verifier.verify("id_token");
+            }))
+        });
+
+        assert!(result.is_err());
+    }
+
+    struct TestIdTokenVerifier;
+
+    impl IdTokenVerifier<String> for TestIdTokenVerifier {
+        async fn verify(&self, id_token: &str) -> Result<String, Error> {
+            tokio::task::yield_now().await;
+
+            if id_token.is_empty() {
+                return Err(Error::IdTokenError {
+                    kind: IdTokenErrorKind::MalformedHeader,
+                    source: None,
+                });
+            }
+
+            Ok(id_token.to_string())
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use jsonwebtoken::jwk::JwkSet;
+use url::Url;
+
+use crate::id_token_verifier::Cache;
+use crate::prelude::*;
+
+/// A [JwkSetClient] that decorates another [JwkSetClient] with a cache, so that the [JwkSet]s of any source (e.g. a
+/// file or a custom one) are fetched at most once per TTL.
+///
+/// The concurrent fetches of the expired [JwkSet] share one fetch of the decorated [JwkSetClient].
+pub struct CachingJwkSetClient<Client, Store = InMemoryJwkSetCacheStore> {
+    /// A decorated [JwkSetClient].
+    client: Client,
+
+    /// A [Cache] of the [JwkSet]s fetched with the `client`.
+    cache: Cache<Store>,
+}
+
+impl<Client, Store> CachingJwkSetClient<Client, Store>
+where
+    Store: JwkSetCacheStore,
+{
+    /// Returns a new instance of the [CachingJwkSetClient] that caches the [JwkSet]s fetched with the given
+    /// [JwkSetClient] in the given [JwkSetCacheStore] for the given `ttl`.
+    pub fn new(client: Client, cache_store: Store, ttl: impl IntoDuration) -> Self {
+        Self {
+            client,
+            cache: Cache::new(cache_store, ttl.into_duration()),
+        }
+    }
+
+    /// Returns a reference to the decorated [JwkSetClient].
+    pub fn inner(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns the TTL of the cached [JwkSet]s.
+    pub fn ttl(&self) -> Duration {
+        self.cache.ttl
+    }
+}
+
+impl<Client, Store> JwkSetClient for CachingJwkSetClient<Client, Store>
+where
+    Client: JwkSetClient + MaybeSync,
+    Store: JwkSetCacheStore + MaybeSync,
+{
+    async fn fetch(&self) -> Result<JwkSet, Error> {
+        if let Some(jwk_set) = self.cache.fresh_jwk_set().await {
+            return Ok((*jwk_set).clone());
+        }
+
+        let _fetch_guard = self.cache.fetch_lock.lock().await;
+
+        // The JWK Set may have been fetched while waiting for the lock.
+        if let Some(jwk_set) = self.cache.fresh_jwk_set().await {
+            return Ok((*jwk_set).clone());
+        }
+
+        let jwk_set = Arc::new(self.client.fetch().await?);
+        self.cache
+            .insert(jwk_set.clone(), self.client.fetched_url())
+            .await;
+
+        Ok((*jwk_set).clone())
+    }
+
+    fn issuer(&self) -> Option<String> {
+        self.client.issuer()
+    }
+
+    fn fetched_url(&self) -> Option<Url> {
+        self.client.fetched_url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::caching_jwk_set_client::*;
+
+    #[tokio::test]
+    async fn test_fetch_respects_ttl() {
+        let number_of_fetches = Arc::new(AtomicUsize::new(0));
+        let client = CachingJwkSetClient::new(
+            InMemoryJwkSetClient {
+                number_of_fetches: number_of_fetches.clone(),
+            },
+            InMemoryJwkSetCacheStore::new(),
+            std::time::Duration::from_millis(200),
+        );
+
+        assert_eq!(client.fetch().await.unwrap(), test_jwk_set());
+        assert_eq!(client.fetch().await.unwrap(), test_jwk_set());
+        assert_eq!(number_of_fetches.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            client.issuer().as_deref(),
+            Some("https://issuer.example.com")
+        );
+
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+        assert_eq!(client.fetch().await.unwrap(), test_jwk_set());
+        assert_eq!(number_of_fetches.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_failure_is_not_cached() {
+        let client = CachingJwkSetClient::new(
+            FailingJwkSetClient,
+            InMemoryJwkSetCacheStore::new(),
+            Duration::minutes(5),
+        );
+
+        for _ in 0..2 {
+            assert!(matches!(
+                client.fetch().await,
+                Err(Error::JwkSetError {
+                    kind: JwkSetErrorKind::JwkSetRequestFailed,
+                    source: _,
+                })
+            ));
+        }
+    }
+
+    /// An in-memory [JwkSetClient] that returns the [test_jwk_set].
+    struct InMemoryJwkSetClient {
+        /// The number of [JwkSetClient::fetch] invocations.
+        number_of_fetches: Arc<AtomicUsize>,
+    }
+
+    impl JwkSetClient for InMemoryJwkSetClient {
+        async fn fetch(&self) -> Result<JwkSet, Error> {
+            self.number_of_fetches.fetch_add(1, Ordering::Relaxed);
+
+            Ok(test_jwk_set())
+        }
+
+        fn issuer(&self) -> Option<String> {
+            Some(String::from("https://issuer.example.com"))
+        }
+    }
+
+    /// A [JwkSetClient] that always fails.
+    struct FailingJwkSetClient;
+
+    impl JwkSetClient for FailingJwkSetClient {
+        async fn fetch(&self) -> Result<JwkSet, Error> {
+            Err(Error::JwkSetError {
+                kind: JwkSetErrorKind::JwkSetRequestFailed,
+                source: "The JWK Set is unavailable".into(),
+            })
+        }
+    }
+
+    fn test_jwk_set() -> JwkSet {
+        serde_json::from_value(serde_json::json!({
+            "keys": [{
+                "kty": "oct",
+                "kid": "test_key_id",
+                "alg": "HS256",
+                "k": "c2VjcmV0",
+            }]
+        }))
+        .unwrap()
+    }
+}
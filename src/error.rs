@@ -9,7 +9,8 @@ pub enum Error {
         /// An [IdTokenErrorKind] of this error.
         kind: IdTokenErrorKind,
 
-        /// An optional source of this error.
+        /// An optional source of this error, reported by [std::error::Error::source].
+        #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
     },
 
@@ -19,31 +20,122 @@ pub enum Error {
         /// A [JwkSetErrorKind] of this error.
         kind: JwkSetErrorKind,
 
-        /// An optional source of this error.
+        /// A source of this error, reported by [std::error::Error::source].
+        #[source]
         source: Box<dyn std::error::Error + Send + Sync + 'static>,
     },
 }
 
+impl Error {
+    /// Returns the [IdTokenErrorKind] of this error if it is an [Error::IdTokenError], e.g. for comparing it in the
+    /// assertions without matching the whole error.
+    pub fn id_token_error_kind(&self) -> Option<&IdTokenErrorKind> {
+        match self {
+            Error::IdTokenError { kind, .. } => Some(kind),
+            Error::JwkSetError { .. } => None,
+        }
+    }
+
+    /// Returns the [JwkSetErrorKind] of this error if it is an [Error::JwkSetError].
+    pub fn jwk_set_error_kind(&self) -> Option<&JwkSetErrorKind> {
+        match self {
+            Error::IdTokenError { .. } => None,
+            Error::JwkSetError { kind, .. } => Some(kind),
+        }
+    }
+
+    /// Returns the [jsonwebtoken::errors::ErrorKind] the ID token fails the decoding or the validation with if this is
+    /// an [Error::IdTokenError] whose source is a [SelectedKeyError] or a [jsonwebtoken::errors::Error], e.g. the
+    /// [jsonwebtoken::errors::ErrorKind::InvalidAudience] of the [IdTokenErrorKind::ValidationError].
+    pub fn validation_error_kind(&self) -> Option<&jsonwebtoken::errors::ErrorKind> {
+        let source = match self {
+            Error::IdTokenError {
+                source: Some(source),
+                ..
+            } => source,
+            _ => return None,
+        };
+
+        match source.downcast_ref::<SelectedKeyError>() {
+            Some(selected_key_error) => Some(selected_key_error.source.kind()),
+            None => source
+                .downcast_ref::<jsonwebtoken::errors::Error>()
+                .map(jsonwebtoken::errors::Error::kind),
+        }
+    }
+}
+
 /// A kind of [Error::IdTokenError].
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum IdTokenErrorKind {
-    /// An error kind that indicates the header of the ID token is malformed.
+    /// An error kind that indicates the `Authorization` header value is not of the `Bearer` scheme or has no token.
+    InvalidAuthorizationHeader,
+
+    /// An error kind that indicates the ID token is not valid UTF-8.
+    InvalidEncoding,
+
+    /// An error kind that indicates the ID token is not a compact JWS of three segments, e.g. it is an encrypted JWE
+    /// of five segments. The source is a [MalformedTokenError].
+    MalformedToken,
+
+    /// An error kind that indicates the header of the ID token is malformed, e.g. it is not a Base64url encoded JSON
+    /// object.
     MalformedHeader,
 
+    /// An error kind that indicates the algorithm (`alg` claim) of the ID token's header is not supported, e.g. it is
+    /// `none`.
+    UnsupportedAlgorithm,
+
+    /// An error kind that indicates the ID token's `typ` header is missing or differs from the required one.
+    InvalidTokenType,
+
     /// An error kind that indicates the ID token's header is missing the key ID (`kid` claim) to use for signature verification.
     MissingKeyId,
 
     /// An error kind that indicates the given ID token's signature verification key is not found.
     UnknownSigningKey,
 
+    /// An error kind that indicates the type of the ID token's signature verification key is not allowed.
+    DisallowedKeyType,
+
+    /// An error kind that indicates the `exp` claim of the ID token is further in the future than the maximum expiry
+    /// horizon, which may indicate a broken issuer.
+    ExpiryTooFarInFuture,
+
+    /// An error kind that indicates the algorithm in the ID token's header differs from the algorithm of its signature verification key.
+    AlgorithmMismatch,
+
+    /// An error kind that indicates none of the keys tried for the ID token without a known key ID validates it. The source is an [AllKeysFailedError].
+    AllKeysFailed,
+
+    /// An error kind that indicates the valid issuer is neither configured nor included in the provider metadata.
+    UnknownIssuer,
+
     /// An error kind that indicates the given ID token has failed the validation.
     ValidationError,
 
+    /// An error kind that indicates the given ID token has a claim that is not allowed. The source is an
+    /// [UnexpectedClaimError].
+    UnexpectedClaim,
+
+    /// An error kind that indicates the given ID token's `at_hash` claim is missing or does not match the access token.
+    InvalidAtHash,
+
+    /// An error kind that indicates the given ID token's `nonce` claim is missing or differs from the expected one.
+    InvalidNonce,
+
+    /// An error kind that indicates the given ID token's `jti` claim was already seen.
+    ReplayedToken,
+
     /// An error kind that indicates the given ID token has an invalid payload.
     InvalidPayload,
 
     /// An error kind for other unexpected errors.
     Unexpected,
+
+    /// A custom error kind named by the mapper applied with
+    /// [JwkBasedJwtIdTokenVerifierBuilder::with_error_kind_mapper](crate::id_token_verifier::JwkBasedJwtIdTokenVerifierBuilder::with_error_kind_mapper).
+    Custom(String),
 }
 
 /// A kind of [Error::JwkSetError].
@@ -57,4 +149,160 @@ pub enum JwkSetErrorKind {
 
     /// An error kind that indicates one of the returned JWKs is invalid.
     InvalidJwk,
+
+    /// An error kind that indicates the fetched JWK Set contains no keys, which may happen transiently during a key
+    /// rotation. The empty JWK Set is not cached.
+    EmptyJwkSet,
+
+    /// An error kind that indicates the provider responded with `429 Too Many Requests`. No requests are sent to its
+    /// origin until the time in its `Retry-After` header, if any, for at most 5 minutes.
+    RateLimited,
+
+    /// An error kind that indicates the `jwks_uri` discovered from the provider metadata does not share its origin,
+    /// which may indicate a tampered metadata document.
+    JwksUriOriginMismatch,
+
+    /// An error kind that indicates a response body exceeds the maximum allowed size.
+    ResponseTooLarge,
+
+    /// An error kind that indicates the whole fetch of the JWK Set, including the auto discovery and the fallback URLs,
+    /// does not complete within the fetch deadline.
+    FetchTimedOut,
+
+    /// An error kind that indicates neither the fetch source nor any of the fallback URLs returns the JWK Set. The
+    /// source is an [AllUrlsFailedError].
+    AllUrlsFailed,
+
+    /// An error kind that indicates no fresh [JwkSet](jsonwebtoken::jwk::JwkSet) containing the signing key of the ID
+    /// token is cached, returned instead of fetching it by
+    /// [JwkBasedJwtIdTokenVerifier::verify_cached_only](crate::id_token_verifier::JwkBasedJwtIdTokenVerifier::verify_cached_only).
+    CacheMiss,
+}
+
+/// An error that indicates the ID token is not a compact JWS, the source of [IdTokenErrorKind::MalformedToken].
+#[derive(Debug, ThisError)]
+#[error("Expected a compact JWS of 3 segments, found {segments} segments (a JWE has 5)")]
+pub struct MalformedTokenError {
+    /// The number of the dot-separated segments of the ID token.
+    pub segments: usize,
+}
+
+/// An error that indicates the ID token fails the validation with the selected JWK, the source of the
+/// [IdTokenErrorKind::ValidationError] and other failures of decoding the ID token with a known JWK.
+#[derive(Debug, ThisError)]
+#[error("{source} (key ID: {key_id:?}, algorithm: {algorithm:?})")]
+pub struct SelectedKeyError {
+    /// An optional key ID of the selected JWK.
+    pub key_id: Option<String>,
+
+    /// An algorithm of the ID token's header.
+    pub algorithm: jsonwebtoken::Algorithm,
+
+    /// An error the ID token fails the validation with, reported by [std::error::Error::source].
+    #[source]
+    pub source: jsonwebtoken::errors::Error,
+}
+
+/// An error that indicates the ID token has a claim that is not allowed, the source of
+/// [IdTokenErrorKind::UnexpectedClaim].
+#[derive(Debug, ThisError)]
+#[error("Unexpected claim: {claim}")]
+pub struct UnexpectedClaimError {
+    /// The name of the unexpected claim.
+    pub claim: String,
+}
+
+/// An error that indicates none of the tried JWKs validates the ID token, the source of [IdTokenErrorKind::AllKeysFailed].
+#[derive(Debug, ThisError)]
+#[error("AllKeysFailedError: {failures:?}")]
+pub struct AllKeysFailedError {
+    /// The key IDs of the tried JWKs with the errors they failed to validate the ID token with.
+    pub failures: Vec<(String, jsonwebtoken::errors::Error)>,
+}
+
+/// An error that indicates none of the tried URLs returns the JWK Set, the source of [JwkSetErrorKind::AllUrlsFailed].
+#[derive(Debug, ThisError)]
+#[error("AllUrlsFailedError: {failures:?}")]
+pub struct AllUrlsFailedError {
+    /// The tried URLs with the errors they failed to return the JWK Set with, the fetch source's one first. The
+    /// credentials in the URLs are redacted.
+    pub failures: Vec<(url::Url, Error)>,
+}
+
+/// An error that indicates no fresh JWK Set containing the signing key is cached, the source of
+/// [JwkSetErrorKind::CacheMiss].
+#[derive(Debug, ThisError)]
+#[error("CacheMissError: {key_id:?}")]
+pub struct CacheMissError {
+    /// The key ID of the signing key of the ID token, if any.
+    pub key_id: Option<String>,
+}
+
+/// An error that indicates an invalid configuration of the ID token verifier.
+#[derive(Debug, ThisError)]
+#[error("ConfigError: {issues:?}")]
+pub struct ConfigError {
+    /// All [ConfigIssue]s found in the configuration, including the warnings.
+    pub issues: Vec<ConfigIssue>,
+}
+
+/// An issue found in the configuration of the ID token verifier.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ConfigIssue {
+    /// A [ConfigIssueKind] of this issue.
+    pub kind: ConfigIssueKind,
+
+    /// A [ConfigIssueSeverity] of this issue.
+    pub severity: ConfigIssueSeverity,
+}
+
+/// A severity of the [ConfigIssue].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ConfigIssueSeverity {
+    /// A severity that indicates a suspicious configuration that still works.
+    Warning,
+
+    /// A severity that indicates a configuration that is insecure or cannot work.
+    Error,
+}
+
+/// A kind of [ConfigIssue].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ConfigIssueKind {
+    /// An issue kind that indicates there are no valid issuers, so only the ID tokens without the `iss` claim are accepted.
+    EmptyValidIssuers,
+
+    /// An issue kind that indicates there is no valid audience, so only the ID tokens without the `aud` claim are accepted.
+    EmptyValidAudience,
+
+    /// An issue kind that indicates the issuer is adopted from the provider metadata, but it is not auto discovered.
+    IssuerFromMetadataWithoutAutoDiscover,
+
+    /// An issue kind that indicates the leeway is negative, so the valid ID tokens are rejected before they expire.
+    NegativeLeeway,
+
+    /// An issue kind that indicates the leeway is longer than 5 minutes, so the expired ID tokens are accepted for long.
+    LongLeeway,
+
+    /// An issue kind that indicates the cache TTL is zero or negative, so the cached JWK Sets are never used.
+    NonPositiveCacheTtl,
+
+    /// An issue kind that indicates the cache TTL is longer than a day, so the rotated keys may be missed for a long time.
+    LongCacheTtl,
+
+    /// An issue kind that indicates the initial JWK Set is configured, but the cache is disabled.
+    InitialJwkSetWithoutCache,
+
+    /// An issue kind that indicates the background refresh is configured, but the cache is disabled.
+    BackgroundRefreshWithoutCache,
+
+    /// An issue kind that indicates the background refresh interval is zero or negative, so it never runs.
+    NonPositiveBackgroundRefreshInterval,
+
+    /// An issue kind that indicates the background refresh interval is not shorter than the cache TTL, so the cached
+    /// JWK Set expires between the refreshes.
+    LongBackgroundRefreshInterval,
+
+    /// An issue kind that indicates the JWK Sets are fetched over a plaintext connection.
+    InsecureFetchSourceUrl,
 }
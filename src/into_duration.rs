@@ -0,0 +1,41 @@
+use chrono::Duration;
+
+/// A conversion into a [chrono::Duration], accepted by the builder options, so that they can be configured with either
+/// a [chrono::Duration] or a [std::time::Duration].
+pub trait IntoDuration {
+    /// Converts this value into a [chrono::Duration].
+    fn into_duration(self) -> Duration;
+}
+
+impl IntoDuration for Duration {
+    fn into_duration(self) -> Duration {
+        self
+    }
+}
+
+/// A [std::time::Duration] that exceeds the maximum [chrono::Duration] is saturated to it.
+impl IntoDuration for std::time::Duration {
+    fn into_duration(self) -> Duration {
+        Duration::from_std(self).unwrap_or(Duration::max_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::into_duration::*;
+
+    #[test]
+    fn test_into_duration() {
+        assert_eq!(Duration::minutes(5).into_duration(), Duration::minutes(5));
+        assert_eq!(
+            std::time::Duration::from_millis(1500).into_duration(),
+            Duration::milliseconds(1500)
+        );
+        assert_eq!(
+            std::time::Duration::MAX.into_duration(),
+            Duration::max_value()
+        );
+    }
+}
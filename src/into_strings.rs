@@ -0,0 +1,62 @@
+/// A conversion into a [Vec] of [String]s, accepted by the builder options, so that they can be configured with either
+/// a single string or a collection of strings.
+pub trait IntoStrings {
+    /// Converts this value into a [Vec] of [String]s.
+    fn into_strings(self) -> Vec<String>;
+}
+
+impl IntoStrings for &str {
+    fn into_strings(self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl IntoStrings for String {
+    fn into_strings(self) -> Vec<String> {
+        vec![self]
+    }
+}
+
+impl<T> IntoStrings for Vec<T>
+where
+    T: Into<String>,
+{
+    fn into_strings(self) -> Vec<String> {
+        self.into_iter().map(Into::into).collect()
+    }
+}
+
+impl<T, const N: usize> IntoStrings for [T; N]
+where
+    T: Into<String>,
+{
+    fn into_strings(self) -> Vec<String> {
+        self.into_iter().map(Into::into).collect()
+    }
+}
+
+impl<T> IntoStrings for &[T]
+where
+    T: Clone + Into<String>,
+{
+    fn into_strings(self) -> Vec<String> {
+        self.iter().cloned().map(Into::into).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::into_strings::*;
+
+    #[test]
+    fn test_into_strings() {
+        let expected = vec![String::from("a"), String::from("b")];
+
+        assert_eq!("a".into_strings(), vec![String::from("a")]);
+        assert_eq!(String::from("a").into_strings(), vec![String::from("a")]);
+        assert_eq!(["a", "b"].into_strings(), expected);
+        assert_eq!(vec!["a", "b"].into_strings(), expected);
+        assert_eq!(expected.clone().into_strings(), expected);
+        assert_eq!(expected.as_slice().into_strings(), expected);
+    }
+}
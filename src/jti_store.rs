@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::prelude::*;
+
+/// A base trait for stores of the seen JWT IDs (`jti` claims), used for rejecting replayed ID tokens.
+pub trait JtiStore {
+    /// Records the given `jti` of an ID token that expires at `exp`.
+    ///
+    /// Returns [Ok(true)] if the `jti` was not seen before, [Ok(false)] if it was, or an [Err(Error)] if the store
+    /// failed to check it. The store is responsible for forgetting the `jti`s of expired ID tokens.
+    fn check_and_insert(
+        &self,
+        jti: &str,
+        exp: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, Error>> + MaybeSend;
+}
+
+/// An in-memory implementation of the [JtiStore] that periodically forgets the `jti`s of expired ID tokens.
+pub struct InMemoryJtiStore {
+    /// An internal state of this store.
+    state: Mutex<InMemoryJtiStoreState>,
+
+    /// A [Duration] between the cleanups of the expired `jti`s.
+    cleanup_interval: Duration,
+}
+
+impl InMemoryJtiStore {
+    /// Returns a new instance of the [InMemoryJtiStore] that forgets the `jti`s of expired ID tokens once per the
+    /// given `cleanup_interval`.
+    pub fn new(cleanup_interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(InMemoryJtiStoreState {
+                expirations_by_jti: HashMap::new(),
+                last_cleanup_at: Utc::now(),
+            }),
+            cleanup_interval,
+        }
+    }
+}
+
+impl Default for InMemoryJtiStore {
+    /// Returns a new instance of the [InMemoryJtiStore] that cleans up the expired `jti`s every minute.
+    fn default() -> Self {
+        Self::new(Duration::minutes(1))
+    }
+}
+
+impl JtiStore for InMemoryJtiStore {
+    async fn check_and_insert(&self, jti: &str, exp: DateTime<Utc>) -> Result<bool, Error> {
+        let now = Utc::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        if now - state.last_cleanup_at >= self.cleanup_interval {
+            state.expirations_by_jti.retain(|_, exp| *exp >= now);
+            state.last_cleanup_at = now;
+        }
+
+        match state.expirations_by_jti.get(jti) {
+            Some(seen_exp) if *seen_exp >= now => Ok(false),
+            _ => {
+                state.expirations_by_jti.insert(jti.to_string(), exp);
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// An internal state of the [InMemoryJtiStore].
+struct InMemoryJtiStoreState {
+    /// Expirations of the ID tokens by their seen `jti`s.
+    expirations_by_jti: HashMap<String, DateTime<Utc>>,
+
+    /// A [DateTime] of the last cleanup of the expired `jti`s.
+    last_cleanup_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::jti_store::*;
+
+    #[tokio::test]
+    async fn test_check_and_insert() {
+        let store = InMemoryJtiStore::default();
+        let exp = Utc::now() + Duration::seconds(60);
+
+        assert!(store.check_and_insert("jti_1", exp).await.unwrap());
+        assert!(!store.check_and_insert("jti_1", exp).await.unwrap());
+        assert!(store.check_and_insert("jti_2", exp).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_of_expired_jtis() {
+        let store = InMemoryJtiStore::new(Duration::zero());
+
+        assert!(store
+            .check_and_insert("expired_jti", Utc::now() - Duration::seconds(1))
+            .await
+            .unwrap());
+        assert!(store
+            .check_and_insert("valid_jti", Utc::now() + Duration::seconds(60))
+            .await
+            .unwrap());
+
+        let state = store.state.lock().unwrap();
+        assert_eq!(state.expirations_by_jti.len(), 1);
+        assert!(state.expirations_by_jti.contains_key("valid_jti"));
+    }
+}
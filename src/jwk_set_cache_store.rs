@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::future::Future;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use url::Url;
+
+use crate::maybe_send::MaybeSend;
+
+/// A base trait for stores of the cached [JwkSet]s, keyed by the [Url] of the source each [JwkSet] was fetched from.
+pub trait JwkSetCacheStore {
+    /// Returns the [CachedJwkSet] stored for the given `source_url`, if any. If [None], returns the one stored without
+    /// a `source_url`, e.g. fetched by a [JwkSetClient](crate::jwk_set_client::JwkSetClient) that does not report it.
+    ///
+    /// The store is not required to check whether the [CachedJwkSet] is expired, it is done by the caller.
+    fn get(
+        &self,
+        source_url: Option<&Url>,
+    ) -> impl Future<Output = Option<CachedJwkSet>> + MaybeSend;
+
+    /// Stores the given [CachedJwkSet] for its `source_url`, replacing only the one stored for the same source. The
+    /// store may evict it once the given `ttl` elapses.
+    fn set(
+        &self,
+        cached_jwk_set: CachedJwkSet,
+        ttl: Duration,
+    ) -> impl Future<Output = ()> + MaybeSend;
+}
+
+/// A [JwkSet] stored in a [JwkSetCacheStore].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CachedJwkSet {
+    /// A cached [JwkSet].
+    pub jwk_set: Arc<JwkSet>,
+
+    /// A [DateTime] when the `jwk_set` was fetched.
+    pub fetched_at: DateTime<Utc>,
+
+    /// An optional [DateTime] when the `jwk_set` expires. If [None], it expires once the TTL of the cache elapses
+    /// since the `fetched_at`.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// An optional [Url] the `jwk_set` was fetched from, e.g. a fallback [Url] or the discovered `jwks_uri`, which keys
+    /// it in the [JwkSetCacheStore], so that the [JwkSet]s of several sources sharing a store are cached side by side.
+    #[serde(default)]
+    pub source_url: Option<Url>,
+}
+
+impl CachedJwkSet {
+    /// Returns the owned [JwkSet] of this [CachedJwkSet], cloning it only if it is still shared.
+    pub fn into_jwk_set(self) -> JwkSet {
+        Arc::try_unwrap(self.jwk_set).unwrap_or_else(|jwk_set| (*jwk_set).clone())
+    }
+}
+
+/// An in-memory implementation of the [JwkSetCacheStore], optionally persisted to a file on targets other than
+/// `wasm32`.
+#[derive(Default)]
+pub struct InMemoryJwkSetCacheStore {
+    /// The stored [CachedJwkSet]s by their source [Url]s.
+    state: RwLock<HashMap<Option<Url>, CachedJwkSet>>,
+
+    /// An optional path to persist the stored [CachedJwkSet]s to.
+    #[cfg(not(target_arch = "wasm32"))]
+    persist_path: Option<PathBuf>,
+}
+
+impl InMemoryJwkSetCacheStore {
+    /// Returns a new empty instance of the [InMemoryJwkSetCacheStore].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new instance of the [InMemoryJwkSetCacheStore] that persists the stored [CachedJwkSet]s to the
+    /// given `persist_path`, so that they survive restarts.
+    ///
+    /// The [CachedJwkSet]s persisted at this path are loaded immediately. Missing or corrupt files are ignored.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn persisted(persist_path: PathBuf) -> Self {
+        Self {
+            state: RwLock::new(Self::load(&persist_path)),
+            persist_path: Some(persist_path),
+        }
+    }
+
+    /// Loads the [CachedJwkSet]s persisted at the given `path` by their source [Url]s, if the file exists and is
+    /// valid. A single [CachedJwkSet] persisted by the earlier versions is loaded as well.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load(path: &Path) -> HashMap<Option<Url>, CachedJwkSet> {
+        let Ok(contents) = std::fs::read(path) else {
+            return HashMap::new();
+        };
+
+        serde_json::from_slice::<Vec<CachedJwkSet>>(&contents)
+            .or_else(|_| serde_json::from_slice::<CachedJwkSet>(&contents).map(|c| vec![c]))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|cached_jwk_set| (cached_jwk_set.source_url.clone(), cached_jwk_set))
+            .collect()
+    }
+
+    /// Persists the given [CachedJwkSet]s to the `persist_path` of this store, if any.
+    ///
+    /// Failures are ignored, as the [JwkSet]s are fetched again after a restart in the worst case.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    async fn persist(&self, state: &HashMap<Option<Url>, CachedJwkSet>) {
+        let path = match &self.persist_path {
+            Some(path) => path,
+            None => return,
+        };
+
+        let cached_jwk_sets = state.values().collect::<Vec<_>>();
+        let write_result = match serde_json::to_vec(&cached_jwk_sets) {
+            Ok(contents) => tokio::fs::write(path, contents).await.map_err(Into::into),
+            Err(e) => Err(Box::<dyn std::error::Error + Send + Sync>::from(e)),
+        };
+
+        if let Err(e) = write_result {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(path = %path.display(), error = %e, "Failed to persist the JWK Set cache");
+        }
+    }
+}
+
+impl JwkSetCacheStore for InMemoryJwkSetCacheStore {
+    async fn get(&self, source_url: Option<&Url>) -> Option<CachedJwkSet> {
+        self.state.read().await.get(&source_url.cloned()).cloned()
+    }
+
+    async fn set(&self, cached_jwk_set: CachedJwkSet, _: Duration) {
+        let mut state = self.state.write().await;
+        state.insert(cached_jwk_set.source_url.clone(), cached_jwk_set);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.persist(&state).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+    use jsonwebtoken::jwk::JwkSet;
+
+    use crate::jwk_set_cache_store::*;
+
+    #[test]
+    fn test_into_jwk_set() {
+        let jwk_set = JwkSet { keys: vec![] };
+        let cached_jwk_set = CachedJwkSet {
+            jwk_set: Arc::new(jwk_set.clone()),
+            fetched_at: Utc::now(),
+            expires_at: None,
+            source_url: None,
+        };
+
+        let shared_jwk_set = cached_jwk_set.jwk_set.clone();
+        assert_eq!(cached_jwk_set.clone().into_jwk_set(), jwk_set);
+        assert_eq!(Arc::strong_count(&shared_jwk_set), 2);
+
+        drop(shared_jwk_set);
+        assert_eq!(cached_jwk_set.into_jwk_set(), jwk_set);
+    }
+
+    #[tokio::test]
+    async fn test_persisted_round_trip() {
+        let persist_path = test_persist_path("persisted_round_trip");
+        let _ = std::fs::remove_file(&persist_path);
+        let first_source_url = Url::parse("https://first.example.com/jwks").unwrap();
+        let second_source_url = Url::parse("https://second.example.com/jwks").unwrap();
+
+        let store = InMemoryJwkSetCacheStore::persisted(persist_path.clone());
+        assert_eq!(store.get(Some(&first_source_url)).await, None);
+
+        let cached_jwk_set = |source_url: &Url| CachedJwkSet {
+            jwk_set: Arc::new(JwkSet { keys: vec![] }),
+            fetched_at: Utc::now(),
+            expires_at: None,
+            source_url: Some(source_url.clone()),
+        };
+        let first_cached_jwk_set = cached_jwk_set(&first_source_url);
+        let second_cached_jwk_set = cached_jwk_set(&second_source_url);
+        for cached_jwk_set in [&first_cached_jwk_set, &second_cached_jwk_set] {
+            store
+                .set(cached_jwk_set.clone(), Duration::seconds(60))
+                .await;
+        }
+
+        let restarted_store = InMemoryJwkSetCacheStore::persisted(persist_path.clone());
+        assert_eq!(
+            restarted_store.get(Some(&first_source_url)).await,
+            Some(first_cached_jwk_set)
+        );
+        assert_eq!(
+            restarted_store.get(Some(&second_source_url)).await,
+            Some(second_cached_jwk_set)
+        );
+        assert_eq!(restarted_store.get(None).await, None);
+
+        std::fs::remove_file(&persist_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_persisted_single_cached_jwk_set() {
+        let persist_path = test_persist_path("persisted_single_cached_jwk_set");
+        let cached_jwk_set = CachedJwkSet {
+            jwk_set: Arc::new(JwkSet { keys: vec![] }),
+            fetched_at: Utc::now(),
+            expires_at: None,
+            source_url: None,
+        };
+        std::fs::write(&persist_path, serde_json::to_vec(&cached_jwk_set).unwrap()).unwrap();
+
+        let store = InMemoryJwkSetCacheStore::persisted(persist_path.clone());
+        assert_eq!(store.get(None).await, Some(cached_jwk_set));
+
+        std::fs::remove_file(&persist_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_persisted_corrupt_file() {
+        let persist_path = test_persist_path("persisted_corrupt_file");
+        std::fs::write(&persist_path, "{ corrupt").unwrap();
+
+        let store = InMemoryJwkSetCacheStore::persisted(persist_path.clone());
+        assert_eq!(store.get(None).await, None);
+
+        std::fs::remove_file(&persist_path).unwrap();
+    }
+
+    /// Returns a unique path in the temporary directory for persisting a store in the given test.
+    fn test_persist_path(test_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "id_token_verifier_{test_name}_{}.json",
+            std::process::id()
+        ))
+    }
+}
@@ -0,0 +1,709 @@
+use std::sync::Arc;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::decode_header;
+use jsonwebtoken::jwk::Jwk;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::{Mutex, RwLock};
+use url::Url;
+
+use crate::prelude::*;
+
+/// A trust store of the [JwkBasedJwtIdTokenVerifier]s of several issuers, e.g. for a federation gateway, that looks up
+/// the key IDs across all of their [JwkSet](jsonwebtoken::jwk::JwkSet)s and verifies each ID token with the verifier
+/// of its issuer.
+///
+/// The verifier of an ID token is the one of the issuer named by its unverified `iss` claim if any, or the first one
+/// whose [JwkSet](jsonwebtoken::jwk::JwkSet) contains its key ID otherwise, so that the key IDs colliding across the
+/// issuers resolve to the issuer of the ID token. Either way, the ID token is fully verified by the selected verifier.
+pub struct JwksTrustStore<
+    Client = HttpBasedJwkSetClient,
+    Store = InMemoryJwkSetCacheStore,
+    Jti = InMemoryJtiStore,
+> where
+    Client: JwkSetClient,
+    Store: JwkSetCacheStore,
+    Jti: JtiStore,
+{
+    /// The issuers with their [JwkBasedJwtIdTokenVerifier]s, in the order they were added.
+    issuers: Vec<(String, JwkBasedJwtIdTokenVerifier<Client, Store, Jti>)>,
+}
+
+impl<Client, Store, Jti> Default for JwksTrustStore<Client, Store, Jti>
+where
+    Client: JwkSetClient,
+    Store: JwkSetCacheStore,
+    Jti: JtiStore,
+{
+    fn default() -> Self {
+        Self { issuers: vec![] }
+    }
+}
+
+impl<Client, Store, Jti> JwksTrustStore<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync,
+    Jti: JtiStore + MaybeSend + MaybeSync,
+{
+    /// Returns a new empty [JwksTrustStore].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the given issuer with the [JwkBasedJwtIdTokenVerifier] of its ID tokens to this trust store. The
+    /// `issuer` is matched against the `iss` claim of the ID tokens, so the verifier is expected to validate it too.
+    ///
+    /// Each verifier is built on its own, so the issuers may use distinct [reqwest::Client]s (e.g. with their own
+    /// proxies, root certificates or timeouts, see [JwkBasedJwtIdTokenVerifierBuilder::with_http_client]) and
+    /// validation options.
+    pub fn with_issuer(
+        mut self,
+        issuer: impl Into<String>,
+        verifier: JwkBasedJwtIdTokenVerifier<Client, Store, Jti>,
+    ) -> Self {
+        self.issuers.push((issuer.into(), verifier));
+        self
+    }
+
+    /// Returns the issuers of this trust store, in the order they were added.
+    pub fn issuers(&self) -> impl Iterator<Item = &str> {
+        self.issuers.iter().map(|(issuer, _)| issuer.as_str())
+    }
+
+    /// Returns the first issuer whose [JwkSet](jsonwebtoken::jwk::JwkSet) contains a [Jwk] of the given `key_id`,
+    /// with that [Jwk], fetching the [JwkSet](jsonwebtoken::jwk::JwkSet)s unless they are cached. The issuers whose
+    /// [JwkSet](jsonwebtoken::jwk::JwkSet)s fail to be fetched are skipped.
+    pub async fn find(&self, key_id: &str) -> Option<(String, Jwk)> {
+        self.find_with_verifier(key_id)
+            .await
+            .map(|(issuer, _, jwk)| (issuer.to_string(), jwk))
+    }
+
+    /// Returns the first issuer whose [JwkSet](jsonwebtoken::jwk::JwkSet) contains a [Jwk] of the given `key_id`,
+    /// with its [JwkBasedJwtIdTokenVerifier] and that [Jwk], like [JwksTrustStore::find].
+    async fn find_with_verifier(
+        &self,
+        key_id: &str,
+    ) -> Option<(&str, &JwkBasedJwtIdTokenVerifier<Client, Store, Jti>, Jwk)> {
+        for (issuer, verifier) in &self.issuers {
+            match verifier.find_jwk(key_id).await {
+                Ok(Some(jwk)) => return Some((issuer, verifier, jwk)),
+                Ok(None) => {}
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(issuer, error = %e, "Failed to fetch the JWK Set of the issuer, skipping");
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the [JwkBasedJwtIdTokenVerifier] of the issuer named by the unverified `iss` claim of the given
+    /// `id_token` if any, or of the issuer found by its key ID with [JwksTrustStore::find] otherwise.
+    async fn verifier(
+        &self,
+        id_token: &str,
+    ) -> Result<&JwkBasedJwtIdTokenVerifier<Client, Store, Jti>, Error> {
+        let issuer_hint = unverified_issuer(id_token);
+        if let Some((_, verifier)) = self
+            .issuers
+            .iter()
+            .find(|(issuer, _)| Some(issuer) == issuer_hint.as_ref())
+        {
+            return Ok(verifier);
+        }
+
+        let header = decode_header(id_token).map_err(|e| Error::IdTokenError {
+            kind: IdTokenErrorKind::MalformedHeader,
+            source: Some(e.into()),
+        })?;
+        let key_id = header.kid.ok_or(Error::IdTokenError {
+            kind: IdTokenErrorKind::MissingKeyId,
+            source: None,
+        })?;
+
+        self.find_with_verifier(&key_id)
+            .await
+            .map(|(_, verifier, _)| verifier)
+            .ok_or(Error::IdTokenError {
+                kind: IdTokenErrorKind::UnknownSigningKey,
+                source: None,
+            })
+    }
+}
+
+impl<Client, Store, Jti, Payload> IdTokenVerifier<Payload> for JwksTrustStore<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync,
+    Jti: JtiStore + MaybeSend + MaybeSync,
+    Payload: DeserializeOwned + MaybeSend + MaybeSync,
+{
+    async fn verify(&self, id_token: &str) -> Result<Payload, Error> {
+        self.verifier(id_token).await?.verify(id_token).await
+    }
+}
+
+/// Returns the `iss` claim of the given `id_token` without verifying it, if any.
+fn unverified_issuer(id_token: &str) -> Option<String> {
+    let payload = id_token.split('.').nth(1)?;
+    let payload = URL_SAFE_NO_PAD.decode(payload).ok()?;
+    let claims = serde_json::from_slice::<Value>(&payload).ok()?;
+
+    claims.get("iss")?.as_str().map(str::to_string)
+}
+
+/// A factory of the [JwkBasedJwtIdTokenVerifier] of each provider of an [IssuerRoster], given the
+/// [FetchSource::AutoDiscover] of its OpenID Provider Metadata.
+type VerifierFactory<Client, Store, Jti> =
+    Arc<dyn Fn(FetchSource) -> JwkBasedJwtIdTokenVerifier<Client, Store, Jti> + Send + Sync>;
+
+/// A [JwksTrustStore] of the issuers auto discovered from a list of OpenID Provider Metadata URLs, e.g. from the
+/// configuration of a gateway that federates many providers instead of registering each of them.
+///
+/// Each provider is added to the trust store under the issuer discovered by its verifier, which is built by the given
+/// factory with the [FetchSource::AutoDiscover] of its metadata URL. The providers that cannot be discovered are
+/// retried by the subsequent [IssuerRoster::refresh]es with an exponential backoff, while the discovered ones are
+/// served meanwhile.
+pub struct IssuerRoster<
+    Client = HttpBasedJwkSetClient,
+    Store = InMemoryJwkSetCacheStore,
+    Jti = InMemoryJtiStore,
+> where
+    Client: JwkSetClient,
+    Store: JwkSetCacheStore,
+    Jti: JtiStore,
+{
+    /// An inner state of this roster.
+    inner: Arc<IssuerRosterInner<Client, Store, Jti>>,
+}
+
+/// An inner state of the [IssuerRoster].
+struct IssuerRosterInner<Client, Store, Jti>
+where
+    Client: JwkSetClient,
+    Store: JwkSetCacheStore,
+    Jti: JtiStore,
+{
+    /// A [VerifierFactory] of the verifiers of the providers.
+    verifier_factory: VerifierFactory<Client, Store, Jti>,
+
+    /// A [Duration] to wait before retrying a provider after its first failed discovery, doubled after each
+    /// subsequent one.
+    initial_backoff: Duration,
+
+    /// A maximum [Duration] to wait before retrying a provider.
+    max_backoff: Duration,
+
+    /// The providers that are not discovered yet, in the order of their metadata URLs.
+    pending_providers: Mutex<Vec<PendingProvider>>,
+
+    /// A [JwksTrustStore] of the discovered providers.
+    trust_store: RwLock<JwksTrustStore<Client, Store, Jti>>,
+}
+
+/// A provider of the [IssuerRoster] that is not discovered yet.
+struct PendingProvider {
+    /// A [Url] of the OpenID Provider Metadata of this provider.
+    metadata_url: Url,
+
+    /// The number of the failed discoveries of this provider.
+    failures: u32,
+
+    /// An optional [DateTime] before which this provider is not retried.
+    retry_at: Option<DateTime<Utc>>,
+}
+
+/// A builder of the [IssuerRoster].
+pub struct IssuerRosterBuilder<Client, Store, Jti>
+where
+    Client: JwkSetClient,
+    Store: JwkSetCacheStore,
+    Jti: JtiStore,
+{
+    /// The [Url]s of the OpenID Provider Metadata of the providers.
+    metadata_urls: Vec<Url>,
+
+    /// A [VerifierFactory] of the verifiers of the providers.
+    verifier_factory: VerifierFactory<Client, Store, Jti>,
+
+    /// A [Duration] to wait before retrying a provider after its first failed discovery.
+    initial_backoff: Duration,
+
+    /// A maximum [Duration] to wait before retrying a provider.
+    max_backoff: Duration,
+
+    /// An optional [Duration] between the refreshes of the [IssuerRoster] in the background.
+    #[cfg(not(target_arch = "wasm32"))]
+    refresh_interval: Option<Duration>,
+}
+
+impl<Client, Store, Jti> IssuerRoster<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync,
+    Jti: JtiStore + MaybeSend + MaybeSync,
+{
+    /// Returns a new [IssuerRosterBuilder] of the providers of the given OpenID Provider Metadata [Url]s, whose
+    /// verifiers are built by the given `verifier_factory` with their [FetchSource::AutoDiscover], e.g. with
+    /// [JwkBasedJwtIdTokenVerifierBuilder::with_issuer_from_metadata] enabled.
+    pub fn builder<F>(
+        metadata_urls: Vec<Url>,
+        verifier_factory: F,
+    ) -> IssuerRosterBuilder<Client, Store, Jti>
+    where
+        F: Fn(FetchSource) -> JwkBasedJwtIdTokenVerifier<Client, Store, Jti>
+            + Send
+            + Sync
+            + 'static,
+    {
+        IssuerRosterBuilder {
+            metadata_urls,
+            verifier_factory: Arc::new(verifier_factory),
+            initial_backoff: Duration::seconds(5),
+            max_backoff: Duration::minutes(5),
+            #[cfg(not(target_arch = "wasm32"))]
+            refresh_interval: None,
+        }
+    }
+
+    /// Discovers the providers that are not discovered yet and whose backoff has elapsed, adding them to the trust
+    /// store of this roster under their discovered issuers. The concurrent refreshes wait for each other.
+    ///
+    /// Returns the metadata [Url]s of the providers that are still not discovered.
+    pub async fn refresh(&self) -> Vec<Url> {
+        let mut pending_providers = self.inner.pending_providers.lock().await;
+
+        let mut index = 0;
+        while index < pending_providers.len() {
+            let provider = &mut pending_providers[index];
+            if provider
+                .retry_at
+                .is_some_and(|retry_at| Utc::now() < retry_at)
+            {
+                index += 1;
+                continue;
+            }
+
+            match self.discover(&provider.metadata_url).await {
+                Ok((issuer, verifier)) => {
+                    self.inner
+                        .trust_store
+                        .write()
+                        .await
+                        .issuers
+                        .push((issuer, verifier));
+                    pending_providers.remove(index);
+                }
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                Err(e) => {
+                    provider.failures += 1;
+                    let backoff = self.backoff(provider.failures);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        metadata_url = %provider.metadata_url,
+                        failures = provider.failures,
+                        retry_in_seconds = backoff.num_seconds(),
+                        error = %e,
+                        "Failed to discover the provider of the issuer roster, retrying later"
+                    );
+
+                    provider.retry_at = Some(Utc::now() + backoff);
+                    index += 1;
+                }
+            }
+        }
+
+        pending_providers
+            .iter()
+            .map(|provider| provider.metadata_url.clone())
+            .collect()
+    }
+
+    /// Returns the discovered issuers of this roster, in the order they were discovered.
+    pub async fn issuers(&self) -> Vec<String> {
+        self.inner
+            .trust_store
+            .read()
+            .await
+            .issuers()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Builds the verifier of the provider of the given metadata [Url] and discovers its issuer, fetching its
+    /// [JwkSet](jsonwebtoken::jwk::JwkSet) into its cache, if any.
+    async fn discover(
+        &self,
+        metadata_url: &Url,
+    ) -> Result<(String, JwkBasedJwtIdTokenVerifier<Client, Store, Jti>), Error> {
+        let verifier = (self.inner.verifier_factory)(FetchSource::AutoDiscover {
+            url: metadata_url.clone(),
+        });
+        verifier.warmup().await?;
+        let issuer = verifier.discovered_issuer().await?;
+
+        Ok((issuer, verifier))
+    }
+
+    /// Returns the [Duration] to wait before retrying a provider after the given number of its failed discoveries.
+    fn backoff(&self, failures: u32) -> Duration {
+        let max_backoff = self.inner.max_backoff;
+        let mut backoff = self.inner.initial_backoff;
+        for _ in 1..failures {
+            if backoff >= max_backoff {
+                break;
+            }
+            backoff = backoff + backoff;
+        }
+
+        backoff.min(max_backoff)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<Client, Store, Jti> IssuerRoster<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync + 'static,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync + 'static,
+    Jti: JtiStore + MaybeSend + MaybeSync + 'static,
+{
+    /// Spawns a job on the current [tokio] runtime that refreshes this roster immediately and then every given
+    /// `interval` until this roster is dropped.
+    fn spawn_periodic_refresh(&self, interval: std::time::Duration) {
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => runtime,
+            Err(_) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    "Periodic refresh of the issuer roster requires a tokio runtime, skipping"
+                );
+                return;
+            }
+        };
+
+        let inner = Arc::downgrade(&self.inner);
+        runtime.spawn(async move {
+            let mut ticks = tokio::time::interval(interval);
+
+            loop {
+                ticks.tick().await;
+
+                match inner.upgrade() {
+                    Some(inner) => {
+                        IssuerRoster { inner }.refresh().await;
+                    }
+                    None => break,
+                }
+            }
+        });
+    }
+}
+
+impl<Client, Store, Jti> IssuerRosterBuilder<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync + 'static,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync + 'static,
+    Jti: JtiStore + MaybeSend + MaybeSync + 'static,
+{
+    /// Applies the given [Duration] to wait before retrying a provider after its first failed discovery, doubled after
+    /// each subsequent one up to the given maximum [Duration], to this builder. Defaults to 5 seconds up to 5 minutes.
+    pub fn with_backoff(
+        mut self,
+        initial_backoff: impl IntoDuration,
+        max_backoff: impl IntoDuration,
+    ) -> IssuerRosterBuilder<Client, Store, Jti> {
+        self.initial_backoff = initial_backoff.into_duration();
+        self.max_backoff = max_backoff.into_duration();
+        self
+    }
+
+    /// Applies the given [Duration] between the refreshes of the [IssuerRoster] in the background to this builder, so
+    /// that the providers that could not be discovered are retried without calling [IssuerRoster::refresh]. The first
+    /// refresh runs immediately once the roster is built. Requires a [tokio] runtime.
+    ///
+    /// By default, the roster is only refreshed with [IssuerRoster::refresh].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_refresh_interval(
+        mut self,
+        refresh_interval: impl IntoDuration,
+    ) -> IssuerRosterBuilder<Client, Store, Jti> {
+        self.refresh_interval = Some(refresh_interval.into_duration());
+        self
+    }
+
+    /// Builds the [IssuerRoster] with no discovered providers, which are discovered by [IssuerRoster::refresh] or by
+    /// the background refreshes, if enabled.
+    pub fn build(self) -> IssuerRoster<Client, Store, Jti> {
+        let pending_providers = self
+            .metadata_urls
+            .into_iter()
+            .map(|metadata_url| PendingProvider {
+                metadata_url,
+                failures: 0,
+                retry_at: None,
+            })
+            .collect();
+
+        let roster = IssuerRoster {
+            inner: Arc::new(IssuerRosterInner {
+                verifier_factory: self.verifier_factory,
+                initial_backoff: self.initial_backoff,
+                max_backoff: self.max_backoff,
+                pending_providers: Mutex::new(pending_providers),
+                trust_store: RwLock::new(JwksTrustStore::new()),
+            }),
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(refresh_interval) = self
+            .refresh_interval
+            .and_then(|refresh_interval| refresh_interval.to_std().ok())
+            .filter(|refresh_interval| !refresh_interval.is_zero())
+        {
+            roster.spawn_periodic_refresh(refresh_interval);
+        }
+
+        roster
+    }
+}
+
+impl<Client, Store, Jti, Payload> IdTokenVerifier<Payload> for IssuerRoster<Client, Store, Jti>
+where
+    Client: JwkSetClient + MaybeSend + MaybeSync,
+    Store: JwkSetCacheStore + MaybeSend + MaybeSync,
+    Jti: JtiStore + MaybeSend + MaybeSync,
+    Payload: DeserializeOwned + MaybeSend + MaybeSync,
+{
+    async fn verify(&self, id_token: &str) -> Result<Payload, Error> {
+        self.inner.trust_store.read().await.verify(id_token).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::jwks_trust_store::*;
+    use crate::test_util::*;
+
+    #[tokio::test]
+    async fn test_find_and_verify_across_issuers() {
+        let rsa_keys = TestKeys::rsa();
+        let ec_keys = TestKeys::ec();
+        let rsa_server = TestJwksServer::start(rsa_keys.jwk_set()).await;
+        let ec_server = TestJwksServer::start(ec_keys.jwk_set()).await;
+        let trust_store = JwksTrustStore::new()
+            .with_issuer("rsa_issuer", test_verifier(&rsa_server, "rsa_issuer"))
+            .with_issuer("ec_issuer", test_verifier(&ec_server, "ec_issuer"));
+
+        let (issuer, jwk) = trust_store.find(ec_keys.key_id()).await.unwrap();
+        assert_eq!(issuer, "ec_issuer");
+        assert_eq!(&jwk, ec_keys.jwk());
+        assert!(trust_store.find("unknown_key_id").await.is_none());
+
+        for (keys, issuer) in [(&rsa_keys, "rsa_issuer"), (&ec_keys, "ec_issuer")] {
+            let claims = test_claims(issuer);
+            let verified_claims: TestClaims =
+                trust_store.verify(&keys.sign(&claims)).await.unwrap();
+            assert_eq!(verified_claims, claims);
+
+            let claims = test_claims("unknown_issuer");
+            let result: Result<TestClaims, Error> = trust_store.verify(&keys.sign(&claims)).await;
+            assert!(matches!(
+                result,
+                Err(Error::IdTokenError {
+                    kind: IdTokenErrorKind::ValidationError,
+                    source: Some(_),
+                })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_prefers_issuer_hint_on_key_id_collision() {
+        let rsa_keys = TestKeys::rsa();
+        let mut colliding_jwk_set = TestKeys::ec().jwk_set();
+        colliding_jwk_set.keys[0].common.key_id = Some(rsa_keys.key_id().to_string());
+        let rsa_server = TestJwksServer::start(rsa_keys.jwk_set()).await;
+        let colliding_server = TestJwksServer::start(colliding_jwk_set).await;
+        let trust_store = JwksTrustStore::new()
+            .with_issuer(
+                "colliding_issuer",
+                test_verifier(&colliding_server, "colliding_issuer"),
+            )
+            .with_issuer("rsa_issuer", test_verifier(&rsa_server, "rsa_issuer"));
+
+        let (issuer, _) = trust_store.find(rsa_keys.key_id()).await.unwrap();
+        assert_eq!(issuer, "colliding_issuer");
+
+        let claims = test_claims("rsa_issuer");
+        let verified_claims: TestClaims =
+            trust_store.verify(&rsa_keys.sign(&claims)).await.unwrap();
+        assert_eq!(verified_claims, claims);
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_per_issuer_http_clients() {
+        let rsa_keys = TestKeys::rsa();
+        let ec_keys = TestKeys::ec();
+        let rsa_server = TestJwksServer::start(rsa_keys.jwk_set()).await;
+        let ec_server = TestJwksServer::start(ec_keys.jwk_set()).await;
+        let rsa_http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .unwrap();
+        let unreachable_proxy_http_client = reqwest::Client::builder()
+            .proxy(reqwest::Proxy::all("http://127.0.0.1:1").unwrap())
+            .build()
+            .unwrap();
+        let trust_store = JwksTrustStore::new()
+            .with_issuer(
+                "rsa_issuer",
+                test_verifier_builder(&rsa_server, "rsa_issuer")
+                    .with_http_client(rsa_http_client)
+                    .build(),
+            )
+            .with_issuer(
+                "ec_issuer",
+                test_verifier_builder(&ec_server, "ec_issuer")
+                    .with_http_client(unreachable_proxy_http_client)
+                    .with_required_typ(String::from("JWT"))
+                    .build(),
+            );
+
+        let claims = test_claims("rsa_issuer");
+        let verified_claims: TestClaims =
+            trust_store.verify(&rsa_keys.sign(&claims)).await.unwrap();
+        assert_eq!(verified_claims, claims);
+        assert_eq!(rsa_server.number_of_requests(), 1);
+
+        let result: Result<TestClaims, Error> = trust_store
+            .verify(&ec_keys.sign(&test_claims("ec_issuer")))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::JwkSetError {
+                kind: JwkSetErrorKind::JwkSetRequestFailed,
+                ..
+            })
+        ));
+        assert_eq!(ec_server.number_of_requests(), 0);
+        assert_eq!(rsa_server.number_of_requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_issuer_roster_from_discovery_list() {
+        let rsa_keys = TestKeys::rsa();
+        let ec_keys = TestKeys::ec();
+        let rsa_server = TestJwksServer::start(rsa_keys.jwk_set()).await;
+        let ec_server = TestJwksServer::start(ec_keys.jwk_set()).await;
+        let unreachable_server = TestJwksServer::start(TestKeys::ed25519().jwk_set()).await;
+        let unreachable_metadata_url = unreachable_server.metadata_url().join("/unknown").unwrap();
+        let metadata_urls = vec![
+            rsa_server.metadata_url(),
+            ec_server.metadata_url(),
+            unreachable_metadata_url.clone(),
+        ];
+        let roster = IssuerRoster::builder(metadata_urls.clone(), discovered_verifier)
+            .with_backoff(chrono::Duration::hours(1), chrono::Duration::hours(1))
+            .build();
+        assert!(roster.issuers().await.is_empty());
+
+        assert_eq!(
+            roster.refresh().await,
+            vec![unreachable_metadata_url.clone()]
+        );
+        assert_eq!(
+            roster.issuers().await,
+            vec![rsa_server.issuer(), ec_server.issuer()]
+        );
+        let unreachable_requests = unreachable_server.number_of_requests();
+        assert!(unreachable_requests > 0);
+
+        assert_eq!(roster.refresh().await, vec![unreachable_metadata_url]);
+        assert_eq!(
+            unreachable_server.number_of_requests(),
+            unreachable_requests
+        );
+
+        for (keys, server) in [(&rsa_keys, &rsa_server), (&ec_keys, &ec_server)] {
+            let claims = test_claims(&server.issuer());
+            let verified_claims: TestClaims = roster.verify(&keys.sign(&claims)).await.unwrap();
+            assert_eq!(verified_claims, claims);
+        }
+
+        let periodic_roster = IssuerRoster::builder(metadata_urls, discovered_verifier)
+            .with_refresh_interval(chrono::Duration::hours(1))
+            .build();
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while periodic_roster.issuers().await.len() < 2 {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    /// Returns a [JwkBasedJwtIdTokenVerifier] of the given [FetchSource] that adopts the issuer discovered from the
+    /// provider metadata.
+    fn discovered_verifier(
+        fetch_source: FetchSource,
+    ) -> JwkBasedJwtIdTokenVerifier<HttpBasedJwkSetClient> {
+        JwkBasedJwtIdTokenVerifier::builder(fetch_source)
+            .with_cache(chrono::Duration::minutes(5))
+            .with_issuer_from_metadata(true)
+            .with_valid_audience("test_aud")
+            .build()
+    }
+
+    /// Returns a [JwkBasedJwtIdTokenVerifier] of the given `issuer` that fetches the [JwkSet] served by the given
+    /// [TestJwksServer].
+    fn test_verifier(
+        server: &TestJwksServer,
+        issuer: &str,
+    ) -> JwkBasedJwtIdTokenVerifier<HttpBasedJwkSetClient> {
+        test_verifier_builder(server, issuer).build()
+    }
+
+    /// Returns a [JwkBasedJwtIdTokenVerifierBuilder] of the verifier returned by [test_verifier].
+    fn test_verifier_builder(
+        server: &TestJwksServer,
+        issuer: &str,
+    ) -> JwkBasedJwtIdTokenVerifierBuilder {
+        JwkBasedJwtIdTokenVerifier::builder(FetchSource::Direct {
+            url: server.jwks_url(),
+        })
+        .with_cache(chrono::Duration::minutes(5))
+        .with_validation_options(vec![issuer.to_string()], vec![String::from("test_aud")])
+    }
+
+    /// Returns the [TestClaims] of the given `issuer`.
+    fn test_claims(issuer: &str) -> TestClaims {
+        TestClaims {
+            iss: issuer.to_string(),
+            aud: String::from("test_aud"),
+            exp: chrono::Utc::now().timestamp() + 60,
+        }
+    }
+
+    /// Test claims signed with the [TestKeys].
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    struct TestClaims {
+        /// An issuer of these claims.
+        iss: String,
+
+        /// An audience of these claims.
+        aud: String,
+
+        /// An expiration timestamp of these claims.
+        exp: i64,
+    }
+}
@@ -1,9 +1,29 @@
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod caching_jwk_set_client;
 pub mod error;
 pub mod id_token_verifier;
+pub mod into_duration;
+pub mod into_strings;
+pub mod jti_store;
+pub mod jwk_set_cache_store;
 pub mod jwk_set_client;
+pub mod jwks_trust_store;
+pub mod maybe_send;
+#[cfg(all(any(test, feature = "test-util"), not(target_arch = "wasm32")))]
+pub mod test_util;
 
 pub mod prelude {
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub use crate::blocking::*;
+    pub use crate::caching_jwk_set_client::*;
     pub use crate::error::*;
     pub use crate::id_token_verifier::*;
+    pub use crate::into_duration::*;
+    pub use crate::into_strings::*;
+    pub use crate::jti_store::*;
+    pub use crate::jwk_set_cache_store::*;
     pub use crate::jwk_set_client::*;
+    pub use crate::jwks_trust_store::*;
+    pub use crate::maybe_send::*;
 }
@@ -0,0 +1,30 @@
+/// A bound that is equivalent to [Send] on all targets except `wasm32`, where it is satisfied by all types.
+///
+/// The futures returned by the traits of this crate are [MaybeSend], as the [reqwest] futures are not [Send] on
+/// `wasm32`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> MaybeSend for T where T: Send + ?Sized {}
+
+/// A bound that is equivalent to [Send] on all targets except `wasm32`, where it is satisfied by all types.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T where T: ?Sized {}
+
+/// A bound that is equivalent to [Sync] on all targets except `wasm32`, where it is satisfied by all types.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> MaybeSync for T where T: Sync + ?Sized {}
+
+/// A bound that is equivalent to [Sync] on all targets except `wasm32`, where it is satisfied by all types.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSync for T where T: ?Sized {}